@@ -31,9 +31,61 @@ where
     }
 }
 
-impl<R: Ranged> Ranged for PartialAxis<R>
+/// Request key points from `inner`, keeping only the ones `in_window` accepts, and growing the
+/// request geometrically - since `inner` has no notion that only part of it is visible, a
+/// request for `max_points` is normally spread across its whole range - until roughly
+/// `max_points` of them actually land inside the window, or until asking for more stops
+/// helping. `max_points` is a ceiling, not a target, so the result is also trimmed back down to
+/// it by even downsampling if the last growth step overshot.
+fn clipped_key_points<R>(
+    inner: &R,
+    max_points: usize,
+    in_window: impl Fn(&R::ValueType) -> bool,
+) -> Vec<R::ValueType>
 where
+    R: Ranged,
     R::ValueType: Clone,
+{
+    if max_points == 0 {
+        return vec![];
+    }
+
+    let mut request = max_points;
+    let mut visible: Vec<_> = inner
+        .key_points(request)
+        .into_iter()
+        .filter(|v| in_window(v))
+        .collect();
+
+    while visible.len() < max_points && request < max_points.saturating_mul(1024).max(1024) {
+        let next_request = request * 2;
+        let next_visible: Vec<_> = inner
+            .key_points(next_request)
+            .into_iter()
+            .filter(|v| in_window(v))
+            .collect();
+
+        if next_visible.len() <= visible.len() {
+            break;
+        }
+
+        request = next_request;
+        visible = next_visible;
+    }
+
+    if visible.len() > max_points {
+        let step = visible.len() as f64 / max_points as f64;
+        visible = (0..max_points)
+            .map(|i| visible[((i as f64 * step) as usize).min(visible.len() - 1)].clone())
+            .collect();
+    }
+
+    visible
+}
+
+impl<R: Ranged> Ranged for PartialAxis<R>
+where
+    R::ValueType: Clone + PartialOrd,
 {
     type ValueType = R::ValueType;
 
@@ -42,7 +94,7 @@ where
     }
 
     fn key_points(&self, max_points: usize) -> Vec<Self::ValueType> {
-        self.0.key_points(max_points)
+        clipped_key_points(&self.0, max_points, |v| *v >= self.1.start && *v <= self.1.end)
     }
 
     fn range(&self) -> Range<Self::ValueType> {
@@ -60,18 +112,39 @@ where
 impl<R: DiscreteRanged> DiscreteRanged for PartialAxis<R>
 where
     R: Ranged,
-    <R as Ranged>::ValueType: Eq + Clone,
+    <R as Ranged>::ValueType: Eq + Clone + PartialOrd,
 {
     fn size(&self) -> usize {
-        self.0.size()
+        let lo = self.0.index_of(&self.1.start).unwrap_or(0);
+        let hi = self
+            .0
+            .index_of(&self.1.end)
+            .unwrap_or_else(|| self.0.size().saturating_sub(1));
+
+        if hi < lo {
+            0
+        } else {
+            hi - lo + 1
+        }
     }
 
     fn index_of(&self, value: &R::ValueType) -> Option<usize> {
-        self.0.index_of(value)
+        if *value < self.1.start || *value > self.1.end {
+            return None;
+        }
+
+        let lo = self.0.index_of(&self.1.start)?;
+        self.0.index_of(value).map(|index| index - lo)
     }
 
     fn from_index(&self, index: usize) -> Option<Self::ValueType> {
-        self.0.from_index(index)
+        let lo = self.0.index_of(&self.1.start)?;
+
+        if index >= self.size() {
+            return None;
+        }
+
+        self.0.from_index(index + lo)
     }
 }
 
@@ -104,3 +177,326 @@ where
 
     Some(PartialAxis(full_range.into(), axis_range.range()))
 }
+
+/// The pixel width of the gap drawn between two segments of a [`BrokenAxis`], unless
+/// overridden with [`BrokenAxis::with_gap`].
+const DEFAULT_BROKEN_AXIS_GAP: i32 = 10;
+
+/// The local pixel scale used to compare positions within the inner coordinate when
+/// [`BrokenAxis`] needs to place a value - this only ever feeds into ratios, so its
+/// magnitude doesn't matter beyond giving `map` enough precision to work with.
+const BROKEN_AXIS_LOCAL_SCALE: i32 = 1_000_000;
+
+/// This axis decorator stitches several non-overlapping sub-ranges of the inner coordinate
+/// onto a single pixel span, leaving a visible gap between each segment. This lets large,
+/// uninteresting stretches of an axis (a long quiet period in a time series, or a single
+/// huge outlier) be collapsed while keeping both ends readable - something the single-window
+/// `PartialAxis` can't express.
+///
+/// The segments must be supplied in ascending order and must not overlap.
+pub struct BrokenAxis<R: Ranged> {
+    inner: R,
+    segments: Vec<Range<R::ValueType>>,
+    weights: Vec<f64>,
+    gap: i32,
+}
+
+impl<R: Ranged> BrokenAxis<R> {
+    fn new(inner: R, segments: Vec<Range<R::ValueType>>, weights: Option<Vec<f64>>) -> Self {
+        let weights = weights.unwrap_or_else(|| vec![1.0; segments.len()]);
+        assert_eq!(
+            weights.len(),
+            segments.len(),
+            "BrokenAxis needs exactly one weight per segment"
+        );
+
+        BrokenAxis {
+            inner,
+            segments,
+            weights,
+            gap: DEFAULT_BROKEN_AXIS_GAP,
+        }
+    }
+
+    /// Override the pixel width of the gap drawn between segments.
+    pub fn with_gap(mut self, gap: i32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Compute the pixel sub-range allotted to each segment within `limit`, splitting the
+    /// space left over after the gaps according to `self.weights`. `limit` may be reversed
+    /// (`limit.0 > limit.1`, as plotters passes for a y-axis); segments and gaps are laid out
+    /// in whichever direction `limit` points, so the returned `(start, end)` pairs may
+    /// themselves have `start > end`.
+    fn segment_pixel_ranges(&self, limit: (i32, i32)) -> Vec<(i32, i32)> {
+        let count = self.segments.len();
+        if count == 0 {
+            return vec![];
+        }
+
+        let direction: i32 = if limit.1 >= limit.0 { 1 } else { -1 };
+        let total_gap = self.gap * (count as i32 - 1).max(0);
+        let available = ((limit.1 - limit.0).abs() - total_gap).max(0) as f64;
+        let total_weight: f64 = self.weights.iter().sum();
+
+        let mut ranges = Vec::with_capacity(count);
+        let mut cursor = limit.0;
+        for (index, weight) in self.weights.iter().enumerate() {
+            let width = if total_weight > 0.0 {
+                (available * weight / total_weight).round() as i32
+            } else {
+                0
+            };
+            let start = cursor;
+            let end = start + direction * width;
+            ranges.push((start, end));
+            cursor = end + if index + 1 < count { direction * self.gap } else { 0 };
+        }
+
+        ranges
+    }
+}
+
+impl<R: Ranged> BrokenAxis<R>
+where
+    R::ValueType: PartialOrd,
+{
+    /// Find the index of the segment that contains `value`, if any.
+    fn segment_of(&self, value: &R::ValueType) -> Option<usize> {
+        self.segments
+            .iter()
+            .position(|segment| *value >= segment.start && *value <= segment.end)
+    }
+}
+
+/// The trait for the types that can be converted into a broken axis
+pub trait IntoBrokenAxis: AsRangedCoord
+where
+    <Self::CoordDescType as Ranged>::ValueType: PartialOrd,
+{
+    /// Make the broken axis
+    ///
+    /// - `segments`: The non-overlapping sub-ranges of the axis that remain visible, given
+    ///   in ascending order
+    /// - `weights`: The relative pixel width given to each segment; `None` splits the space
+    ///   evenly
+    /// - **returns**: The converted range specification
+    fn broken_axis(
+        self,
+        segments: Vec<Range<<Self::CoordDescType as Ranged>::ValueType>>,
+        weights: Option<Vec<f64>>,
+    ) -> BrokenAxis<Self::CoordDescType> {
+        BrokenAxis::new(self.into(), segments, weights)
+    }
+}
+
+impl<R: AsRangedCoord> IntoBrokenAxis for R where <R::CoordDescType as Ranged>::ValueType: PartialOrd
+{}
+
+impl<R: Ranged> Ranged for BrokenAxis<R>
+where
+    R::ValueType: Clone + PartialOrd,
+{
+    type ValueType = R::ValueType;
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        let ranges = self.segment_pixel_ranges(limit);
+        if ranges.is_empty() {
+            return limit.0;
+        }
+
+        let scale = (0, BROKEN_AXIS_LOCAL_SCALE);
+
+        if let Some(index) = self.segment_of(value) {
+            let segment = &self.segments[index];
+            let (seg_start, seg_end) = ranges[index];
+
+            let lo = self.inner.map(&segment.start, scale);
+            let hi = self.inner.map(&segment.end, scale);
+            let at = self.inner.map(value, scale);
+
+            let frac = if hi != lo {
+                (at - lo) as f64 / (hi - lo) as f64
+            } else {
+                0.0
+            };
+
+            return seg_start + ((seg_end - seg_start) as f64 * frac).round() as i32;
+        }
+
+        // `value` falls in one of the omitted intervals: before the first segment, after
+        // the last one, or in a gap between two consecutive segments. Snap to whichever
+        // visible edge is closest.
+        if *value < self.segments[0].start {
+            return ranges[0].0;
+        }
+
+        for index in 0..self.segments.len().saturating_sub(1) {
+            if *value > self.segments[index].end && *value < self.segments[index + 1].start {
+                let at = self.inner.map(value, scale);
+                let left = self.inner.map(&self.segments[index].end, scale);
+                let right = self.inner.map(&self.segments[index + 1].start, scale);
+
+                return if (at - left).abs() <= (right - at).abs() {
+                    ranges[index].1
+                } else {
+                    ranges[index + 1].0
+                };
+            }
+        }
+
+        ranges[ranges.len() - 1].1
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<Self::ValueType> {
+        if self.segments.is_empty() || max_points == 0 {
+            return vec![];
+        }
+
+        // Each segment is typically a tiny slice of `self.inner`'s full range, so the inner
+        // range's "nice" key points will rarely fall inside it on the first try - clip each
+        // segment the same way `PartialAxis` does, rather than asking `self.inner` for key
+        // points just once and filtering.
+        let per_segment = (max_points / self.segments.len()).max(1);
+
+        self.segments
+            .iter()
+            .flat_map(|segment| {
+                clipped_key_points(&self.inner, per_segment, |v| {
+                    *v >= segment.start && *v <= segment.end
+                })
+            })
+            .collect()
+    }
+
+    fn range(&self) -> Range<Self::ValueType> {
+        match (self.segments.first(), self.segments.last()) {
+            (Some(first), Some(last)) => first.start.clone()..last.end.clone(),
+            _ => self.inner.range(),
+        }
+    }
+
+    fn axis_pixel_range(&self, limit: (i32, i32)) -> Range<i32> {
+        let ranges = self.segment_pixel_ranges(limit);
+        match (ranges.first(), ranges.last()) {
+            (Some(first), Some(last)) => first.0.min(last.1)..first.0.max(last.1),
+            _ => limit.0.min(limit.1)..limit.0.max(limit.1),
+        }
+    }
+}
+
+/// Make a partial axis directly from the full range and the visible sub-range.
+///
+/// Unlike [`make_partial_axis`], this doesn't cast either endpoint to `f64`, so it works for
+/// any value type `T` whose `Range<T>` converts into a coordinate (`Range<T>: AsRangedCoord`) -
+/// not only numeric ones.
+///
+/// - `full_range`: The full range of the underlying coordinate
+/// - `axis_range`: The sub-range of `full_range` that should actually be displayed
+/// - **returns**: The partial axis created from the input
+pub fn make_partial_axis_from_ranges<T>(
+    full_range: Range<T>,
+    axis_range: Range<T>,
+) -> PartialAxis<<Range<T> as AsRangedCoord>::CoordDescType>
+where
+    Range<T>: AsRangedCoord,
+{
+    let axis_range: <Range<T> as AsRangedCoord>::CoordDescType = axis_range.into();
+
+    PartialAxis(full_range.into(), axis_range.range())
+}
+
+/// Make a partial axis based on the percentage of the visible portion, like
+/// [`make_partial_axis`], but for coordinate types that aren't `num_traits::NumCast` - again,
+/// dates, durations, and the like.
+///
+/// - `axis_range`: The range specification
+/// - `part`: The visible part of the axis. Each value is from [0.0, 1.0]
+/// - `interpolate`: Given `axis_range` and a fraction `t`, produces the value that sits at `t`
+///   along `axis_range`'s own span - e.g. `axis_range.start + (axis_range.end -
+///   axis_range.start) * t` for a type with that arithmetic available. `t` may fall outside
+///   `[0.0, 1.0]`; `interpolate` is expected to extrapolate rather than clamp, since that's how
+///   the full range's endpoints are located from the visible one
+/// - **returns**: The partial axis created from the input, or `None` when `part` has zero
+///   width (`part.start == part.end`)
+pub fn make_partial_axis_with<T>(
+    axis_range: Range<T>,
+    part: Range<f64>,
+    mut interpolate: impl FnMut(&Range<T>, f64) -> T,
+) -> Option<PartialAxis<<Range<T> as AsRangedCoord>::CoordDescType>>
+where
+    Range<T>: AsRangedCoord,
+{
+    let span = part.end - part.start;
+    if span == 0.0 {
+        return None;
+    }
+
+    let full_start = interpolate(&axis_range, -part.start / span);
+    let full_end = interpolate(&axis_range, (1.0 - part.start) / span);
+
+    Some(make_partial_axis_from_ranges(full_start..full_end, axis_range))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn partial_axis_key_points_are_clipped_and_bounded() {
+        let axis = (0..100).partial_axis(20..40);
+        let points = axis.key_points(10);
+
+        assert!(points.iter().all(|v| (20..=40).contains(v)));
+        assert!(points.len() <= 10);
+        assert!(!points.is_empty());
+    }
+
+    #[test]
+    fn broken_axis_places_values_in_their_segment_and_snaps_gaps() {
+        let axis = (0..1000).broken_axis(vec![0..10, 990..1000], None);
+
+        // A value inside the tiny first segment should land within that segment's own
+        // pixel slice, not be smeared across the whole `(0, 500)` span as if the second
+        // segment didn't carve out half the width.
+        let left_pixel = axis.map(&5, (0, 500));
+        assert!(left_pixel < 250);
+
+        let right_pixel = axis.map(&995, (0, 500));
+        assert!(right_pixel > 250);
+
+        // A value in the omitted middle snaps to one of the two segment edges.
+        let ranges = axis.segment_pixel_ranges((0, 500));
+        let gap_pixel = axis.map(&500, (0, 500));
+        assert!(gap_pixel == ranges[0].1 || gap_pixel == ranges[1].0);
+
+        // The same axis on a reversed (y-axis style) limit must not collapse every
+        // segment onto a single pixel.
+        let reversed_left = axis.map(&5, (500, 0));
+        let reversed_right = axis.map(&995, (500, 0));
+        assert_ne!(reversed_left, reversed_right);
+
+        let reversed_pixel_range = axis.axis_pixel_range((500, 0));
+        assert!(reversed_pixel_range.start <= reversed_pixel_range.end);
+    }
+
+    #[test]
+    fn make_partial_axis_with_matches_make_partial_axis_and_rejects_empty_part() {
+        let via_cast = make_partial_axis(20.0..40.0, 0.2..0.4).unwrap();
+        let via_interpolate = make_partial_axis_with(20.0..40.0, 0.2..0.4, |range: &Range<f64>, t| {
+            range.start + (range.end - range.start) * t
+        })
+        .unwrap();
+
+        assert_eq!(
+            via_cast.map(&30.0, (0, 1000)),
+            via_interpolate.map(&30.0, (0, 1000))
+        );
+
+        assert!(make_partial_axis_with(20.0..40.0, 0.4..0.4, |range: &Range<f64>, t| {
+            range.start + (range.end - range.start) * t
+        })
+        .is_none());
+    }
+}